@@ -0,0 +1,363 @@
+//! An optional memoized query layer with dependency-tracked invalidation.
+//!
+//! The cache sits in front of an [`Executor`] and, modelled after incremental-recomputation
+//! engines, keys a materialized result set by the query it came from. A repeated `fetch` of the
+//! same SQL text and arguments is served from memory instead of re-hitting the database, until a
+//! write to one of the tables the query read invalidates it.
+//!
+//! ```rust,ignore
+//! let cache = QueryCache::new();
+//! let mut exec = CachingExecutor::new(&mut conn, cache.clone());
+//!
+//! // First call drives the real cursor; second is served from the cache.
+//! let a = exec.fetch_cached(query("SELECT id, title FROM articles")).await?;
+//! let b = exec.fetch_cached(query("SELECT id, title FROM articles")).await?;
+//!
+//! // A write to `articles` drops every cached query that read it.
+//! exec.execute("UPDATE articles SET title = '' WHERE id = 1").await?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use either::Either;
+use futures_core::future::BoxFuture;
+
+use crate::cursor::{private, BoxOwnedRow, Cursor, OwnedRow, ToOwnedRow};
+use crate::database::Database;
+use crate::executor::{Execute, Executor};
+use crate::pool::Pool;
+use crate::row::HasRow;
+
+/// Derives the cache key of a query: its SQL text and the encoded bytes of its bound arguments.
+///
+/// Keying on argument bytes must match the exact encoding the driver sends to the server, which
+/// only the database's `Arguments` type can produce — there is no generic byte view of a bound
+/// value. Rather than invent one, caching is gated on this trait: it is implemented here for plain
+/// `&str` queries (no arguments), and a database integration implements it for its parameterized
+/// query type by reusing its real argument encoder.
+pub trait CacheKeyArguments<'q, DB: Database>: Execute<'q, DB> {
+    /// Returns `(sql, encoded_arguments)` for this query.
+    fn cache_key(&self) -> (String, Vec<u8>);
+}
+
+impl<'q, DB: Database> CacheKeyArguments<'q, DB> for &'q str
+where
+    &'q str: Execute<'q, DB>,
+{
+    fn cache_key(&self) -> (String, Vec<u8>) {
+        // A raw SQL string carries no bound arguments.
+        ((*self).to_owned(), Vec::new())
+    }
+}
+
+/// The identity of a cached query: its normalized SQL text and the encoded bytes of its arguments.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    sql: String,
+    arguments: Vec<u8>,
+}
+
+impl CacheKey {
+    fn new(sql: String, arguments: Vec<u8>) -> Self {
+        CacheKey {
+            sql: normalize_sql(&sql),
+            arguments,
+        }
+    }
+}
+
+struct CacheEntry<DB: Database> {
+    rows: Vec<BoxOwnedRow<DB>>,
+    reads: HashSet<String>,
+}
+
+/// A shared, cheaply-clonable store of materialized result sets keyed by query identity.
+pub struct QueryCache<DB: Database> {
+    entries: Arc<Mutex<HashMap<CacheKey, Arc<CacheEntry<DB>>>>>,
+}
+
+impl<DB: Database> Clone for QueryCache<DB> {
+    fn clone(&self) -> Self {
+        QueryCache {
+            entries: Arc::clone(&self.entries),
+        }
+    }
+}
+
+impl<DB: Database> Default for QueryCache<DB> {
+    fn default() -> Self {
+        QueryCache::new()
+    }
+}
+
+impl<DB: Database> QueryCache<DB> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        QueryCache {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Drops every cached query whose read-set contains `table`.
+    ///
+    /// This is called automatically by [`CachingExecutor`] for the target of each write, but may
+    /// also be invoked directly when a table is mutated out-of-band.
+    pub fn invalidate_table(&self, table: &str) {
+        let table = table.to_ascii_lowercase();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, entry| !entry.reads.contains(&table));
+    }
+
+    /// Empties the cache entirely.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<Arc<CacheEntry<DB>>> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: CacheKey, entry: Arc<CacheEntry<DB>>) {
+        self.entries.lock().unwrap().insert(key, entry);
+    }
+}
+
+/// An [`Executor`] wrapper that memoizes read queries and invalidates on writes.
+pub struct CachingExecutor<E, DB: Database> {
+    inner: E,
+    cache: QueryCache<DB>,
+}
+
+impl<E, DB: Database> CachingExecutor<E, DB> {
+    /// Wraps `inner`, sharing `cache` across every executor built from the same handle.
+    pub fn new(inner: E, cache: QueryCache<DB>) -> Self {
+        CachingExecutor { inner, cache }
+    }
+}
+
+impl<'e, E, DB> CachingExecutor<E, DB>
+where
+    DB: Database,
+    E: Executor<'e, Database = DB>,
+{
+    /// Fetches `query`, serving a previously materialized result set on a hit and driving the real
+    /// [`Cursor`] to completion (collecting owned rows) on a miss.
+    pub fn fetch_cached<'q, Q>(
+        &'e mut self,
+        query: Q,
+    ) -> BoxFuture<'e, crate::Result<DB, CachedCursor<DB>>>
+    where
+        Q: CacheKeyArguments<'q, DB> + 'e,
+        for<'r> <DB as HasRow<'r>>::Row: ToOwnedRow<DB>,
+    {
+        let (sql, arguments) = query.cache_key();
+        let key = CacheKey::new(sql, arguments);
+
+        Box::pin(async move {
+            if let Some(entry) = self.cache.get(&key) {
+                return Ok(CachedCursor::replay(Arc::clone(&entry)));
+            }
+
+            let reads = read_set(&key.sql);
+
+            let mut cursor = self.inner.fetch(query);
+            let mut rows = Vec::new();
+            while let Some(row) = cursor.next().await? {
+                rows.push(row.to_owned_row());
+            }
+
+            let entry = Arc::new(CacheEntry { rows, reads });
+            self.cache.insert(key, Arc::clone(&entry));
+
+            Ok(CachedCursor::replay(entry))
+        })
+    }
+
+    /// Executes a write query on the inner executor and invalidates the cache accordingly.
+    ///
+    /// A write whose target table can be resolved drops only the entries that read it; any write
+    /// form the parser cannot fully resolve (`REPLACE`, `MERGE`, `TRUNCATE`, CTE-prefixed updates,
+    /// multi-statement batches, …) fails closed and clears the whole cache, so a stale entry is
+    /// never served.
+    pub fn execute<'q, Q>(&'e mut self, query: Q) -> BoxFuture<'e, crate::Result<DB, u64>>
+    where
+        Q: CacheKeyArguments<'q, DB> + 'e,
+    {
+        let (sql, _) = query.cache_key();
+        match write_invalidation(&sql) {
+            Invalidation::Table(table) => self.cache.invalidate_table(&table),
+            Invalidation::All => self.cache.clear(),
+        }
+
+        self.inner.execute(query)
+    }
+}
+
+/// A synthetic [`Cursor`] that replays a cached result set with no live connection.
+///
+/// It is a fully-fledged `Cursor`, so a cached fetch is interchangeable with a real one and can be
+/// driven through [`CursorExt`]. Replayed result sets hold only rows — there are no
+/// command-complete packets to surface — so its [`QueryResult`](Cursor::QueryResult) is
+/// uninhabited and its `next_many` re-maps every row to [`Either::Right`].
+///
+/// [`CursorExt`]: crate::cursor::CursorExt
+/// [`Either::Right`]: either::Either::Right
+pub struct CachedCursor<DB: Database> {
+    entry: Arc<CacheEntry<DB>>,
+    pos: usize,
+}
+
+impl<DB: Database> CachedCursor<DB> {
+    fn replay(entry: Arc<CacheEntry<DB>>) -> Self {
+        CachedCursor { entry, pos: 0 }
+    }
+}
+
+impl<DB: Database> private::Sealed for CachedCursor<DB> {}
+
+impl<'c, 'q, DB: Database> Cursor<'c, 'q> for CachedCursor<DB> {
+    type Database = DB;
+    type QueryResult = Infallible;
+
+    fn from_pool<E>(_pool: &Pool<<DB as Database>::Connection>, _query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, DB>,
+    {
+        // A cached cursor is materialized by `CachingExecutor::fetch_cached`, never built directly
+        // from a pool or connection.
+        unreachable!("CachedCursor is constructed via CachingExecutor::fetch_cached")
+    }
+
+    fn from_connection<E>(_connection: &'c mut <DB as Database>::Connection, _query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, DB>,
+    {
+        unreachable!("CachedCursor is constructed via CachingExecutor::fetch_cached")
+    }
+
+    fn next<'cur>(
+        &'cur mut self,
+    ) -> BoxFuture<'cur, crate::Result<DB, Option<<DB as HasRow<'cur>>::Row>>> {
+        Box::pin(async move {
+            let row = self.entry.rows.get(self.pos).map(|owned| owned.as_row());
+            if row.is_some() {
+                self.pos += 1;
+            }
+            Ok(row)
+        })
+    }
+
+    fn next_many<'cur>(
+        &'cur mut self,
+    ) -> BoxFuture<'cur, crate::Result<DB, Option<Either<Infallible, <DB as HasRow<'cur>>::Row>>>>
+    {
+        // A replayed result set carries no command-complete packets, so every element is a row.
+        Box::pin(async move { Ok(self.next().await?.map(Either::Right)) })
+    }
+}
+
+/// How a write should invalidate the cache.
+enum Invalidation {
+    /// Drop only the entries that read this table.
+    Table(String),
+    /// Drop everything — the write could not be resolved to a single target table.
+    All,
+}
+
+/// Normalizes SQL text so trivially-different spellings of the same query share a cache entry:
+/// leading/trailing whitespace is trimmed and internal runs of whitespace are collapsed.
+fn normalize_sql(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Extracts the set of tables a read query touches, lower-cased.
+///
+/// Every `FROM`/`JOIN` in the statement is inspected — including the ones inside subqueries, since
+/// the scan walks all tokens — and comma-separated table lists (`FROM a, b`) are followed to their
+/// end. Parsing is best-effort; writes the parser cannot resolve fail closed (see
+/// [`write_invalidation`]), but a table the scan misses — such as the second table of
+/// `FROM a x, b y`, where an alias precedes the comma — is absent from the read set, and a write
+/// that resolves to that table does not drop the entry.
+fn read_set(sql: &str) -> HashSet<String> {
+    let mut tables = HashSet::new();
+    let tokens: Vec<&str> = sql.split_whitespace().collect();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let keyword = tokens[i].to_ascii_lowercase();
+        if keyword == "from" || keyword == "join" {
+            // Collect the target list that follows, taking another table after each comma.
+            i += 1;
+            while let Some(token) = tokens.get(i) {
+                let ident = table_ident(token);
+                if !ident.is_empty() {
+                    tables.insert(ident);
+                }
+
+                // A comma — trailing this token or leading the next — means another table follows.
+                let continues = token.ends_with(',')
+                    || matches!(tokens.get(i + 1), Some(next) if next.starts_with(','));
+                i += 1;
+                if !continues {
+                    break;
+                }
+                // Step over a standalone comma token.
+                if matches!(tokens.get(i), Some(token) if *token == ",") {
+                    i += 1;
+                }
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    tables
+}
+
+/// Decides how a write statement invalidates the cache, failing closed on anything it cannot
+/// resolve to exactly one target table.
+fn write_invalidation(sql: &str) -> Invalidation {
+    let trimmed = sql.trim().trim_end_matches(';').trim();
+
+    // A multi-statement batch can touch any number of tables; don't try to resolve it.
+    if trimmed.contains(';') {
+        return Invalidation::All;
+    }
+
+    let tokens: Vec<&str> = trimmed.split_whitespace().collect();
+    let first = match tokens.first() {
+        Some(token) => token.to_ascii_lowercase(),
+        None => return Invalidation::All,
+    };
+
+    let idx = match first.as_str() {
+        "insert" | "delete" => tokens
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case("into") || t.eq_ignore_ascii_case("from"))
+            .map(|p| p + 1),
+        "update" => Some(1),
+        // REPLACE, MERGE, TRUNCATE, CTE-prefixed `WITH … UPDATE`, and anything else unrecognized
+        // leave the target ambiguous — clear the whole cache rather than risk a stale read.
+        _ => None,
+    };
+
+    match idx.and_then(|i| tokens.get(i)).map(|name| table_ident(name)) {
+        Some(table) if !table.is_empty() => Invalidation::Table(table),
+        _ => Invalidation::All,
+    }
+}
+
+/// Strips quoting, parentheses and `;`/`,` punctuation from a table reference; a column list
+/// glued to the name (`t(a,`) is cut off at its `(`.
+fn table_ident(token: &str) -> String {
+    token
+        .split('(')
+        .next()
+        .unwrap_or("")
+        .trim_matches(|c| c == '"' || c == '`' || c == '\'' || c == ';' || c == ',' || c == ')')
+        .to_ascii_lowercase()
+}
@@ -1,6 +1,12 @@
 //! Contains the `Cursor` trait.
 
+use std::collections::VecDeque;
+use std::future::Future;
+use std::marker::PhantomData;
+
+use either::Either;
 use futures_core::future::BoxFuture;
+use futures_core::stream::Stream;
 
 use crate::database::Database;
 use crate::executor::Execute;
@@ -46,6 +52,14 @@ where
     /// The `Database` this `Cursor` is implemented for.
     type Database: Database;
 
+    /// The per-statement result metadata (rows affected, last insert id) emitted by
+    /// [`next_many`](Cursor::next_many) between rows.
+    ///
+    /// Driver cursors set this to their database's command-complete type; adapters forward their
+    /// inner cursor's type unchanged. It is `Send` so buffered and cached result sets that retain
+    /// it remain `Send`.
+    type QueryResult: Send;
+
     #[doc(hidden)]
     fn from_pool<E>(pool: &Pool<<Self::Database as Database>::Connection>, query: E) -> Self
     where
@@ -65,8 +79,405 @@ where
     fn next<'cur>(
         &'cur mut self,
     ) -> BoxFuture<'cur, crate::Result<Self::Database, Option<<Self::Database as HasRow<'cur>>::Row>>>;
+
+    /// Creates a future that attempts to resolve the next element in the cursor, yielding either
+    /// the result metadata of a completed statement or a single row, in execution order.
+    ///
+    /// Where [`next`] silently folds away the command-complete packets between rows, `next_many`
+    /// surfaces them as [`Either::Left`], carrying a [`QueryResult`] with `rows_affected()` and
+    /// `last_insert_id()`. This is what backs [`Executor::fetch_many`] and lets a consumer observe
+    /// the effect of each statement in a multi-statement or `RETURNING` batch:
+    ///
+    /// ```rust,ignore
+    /// while let Some(step) = cursor.next_many().await? {
+    ///     match step {
+    ///         Either::Left(done) => affected += done.rows_affected(),
+    ///         Either::Right(row) => rows.push(Article::from_row(row)?),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`next`]: Cursor::next
+    /// [`QueryResult`]: Cursor::QueryResult
+    /// [`Executor::fetch_many`]: crate::executor::Executor::fetch_many
+    ///
+    /// There is deliberately no default implementation: a blanket
+    /// `Box::pin(async { Ok(self.next().await?.map(Either::Right)) })` would fold away the very
+    /// command-complete packets this method exists to surface, so every `Cursor` must decide how it
+    /// interleaves them. Driver cursors yield each statement's [`QueryResult`] as [`Either::Left`]
+    /// in execution order; adapters and replay cursors that carry no such metadata re-map their
+    /// rows to [`Either::Right`] explicitly.
+    fn next_many<'cur>(
+        &'cur mut self,
+    ) -> BoxFuture<
+        'cur,
+        crate::Result<
+            Self::Database,
+            Option<Either<Self::QueryResult, <Self::Database as HasRow<'cur>>::Row>>,
+        >,
+    >;
+}
+
+/// A fully-owned row, detached from the connection's current-row buffer so it can outlive the
+/// cursor that produced it.
+///
+/// A driver [`Row`] borrows the single buffer that the *next* `next()` overwrites, so it cannot be
+/// retained past one poll. Anything that keeps rows around — a buffered read-ahead, a cached result
+/// set, a `Stream` collected into a `Vec` — copies them out with [`ToOwnedRow::to_owned_row`] and
+/// borrows them back with [`as_row`](OwnedRow::as_row) on demand.
+///
+/// [`Row`]: crate::row::Row
+pub trait OwnedRow<DB: Database>: Send + Sync + 'static {
+    /// Borrows this owned row as the database's native [`Row`] type.
+    ///
+    /// [`Row`]: crate::row::Row
+    fn as_row<'r>(&'r self) -> <DB as HasRow<'r>>::Row;
+}
+
+/// A heap-allocated, connection-detached row, the owned form produced by
+/// [`ToOwnedRow::to_owned_row`]. It is `Send + Sync + 'static` through [`OwnedRow`]'s supertraits,
+/// so it can rest in a buffer or a shared cache.
+pub type BoxOwnedRow<DB> = Box<dyn OwnedRow<DB>>;
+
+/// Copies a borrowed driver [`Row`] out of the connection's current-row buffer into an owned
+/// [`OwnedRow`].
+///
+/// Implemented by each database's row type in its driver crate, alongside its [`OwnedRow`]; the
+/// core read-ahead ([`BufferedCursor`]), streaming ([`CursorStream`]) and caching adapters use it
+/// to retain rows past the single poll their borrow is valid for.
+///
+/// [`Row`]: crate::row::Row
+pub trait ToOwnedRow<DB: Database> {
+    /// Copies `self` into an owned, heap-allocated row.
+    fn to_owned_row(&self) -> BoxOwnedRow<DB>;
+}
+
+/// Extension methods for [`Cursor`] that adapt the manual `next()` future into the
+/// `futures` combinator ecosystem.
+///
+/// This trait is blanket-implemented for every `Cursor`, so it is brought into scope by
+/// `use sqlx::cursor::CursorExt;` and used directly:
+///
+/// ```rust,ignore
+/// use futures_util::TryStreamExt;
+///
+/// let titles: Vec<_> = cursor
+///     .into_stream()
+///     .try_filter(|row| futures_util::future::ready(!row.as_row().get::<&str, _>("title").is_empty()))
+///     .try_collect()
+///     .await?;
+/// ```
+///
+/// Once a `Cursor` has been turned into a [`CursorStream`], the full `map`/`try_map`/`try_filter`
+/// combinator set from [`futures_util::StreamExt`] and [`futures_util::TryStreamExt`] is available.
+pub trait CursorExt<'c, 'q>: Cursor<'c, 'q> {
+    /// Wraps this cursor into a [`futures_core::Stream`] that yields one fully-owned row per poll.
+    ///
+    /// A driver [`Row`] borrows the connection's current-row buffer, which the next poll
+    /// overwrites, so the stream cannot hand out borrowed rows that a combinator like
+    /// `try_collect` would alias. Instead each row is copied into an owned [`BoxOwnedRow`] eagerly,
+    /// before the borrow is released; callers read columns back through [`OwnedRow::as_row`]. To
+    /// decode straight into a domain type, prefer [`from_row`](CursorExt::from_row).
+    ///
+    /// [`Row`]: crate::row::Row
+    fn into_stream(self) -> CursorStream<'c, 'q, Self>
+    where
+        Self: Sized,
+    {
+        CursorStream {
+            cursor: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Projects each row through `f` as it is produced, yielding owned `Result<T>` items and
+    /// releasing the borrow of the underlying row before the next poll.
+    ///
+    /// The conversion closure runs inline on `Some(row)`, so the returned `T` is detached from the
+    /// connection buffer and may be pushed into a `Vec` or forwarded through a `Stream` without
+    /// fighting the row's per-poll lifetime.
+    fn map_rows<T, F>(self, f: F) -> TypedCursor<'c, 'q, Self, T, F>
+    where
+        Self: Sized,
+        F: for<'cur> FnMut(
+            &<Self::Database as HasRow<'cur>>::Row,
+        ) -> crate::Result<Self::Database, T>,
+    {
+        TypedCursor {
+            cursor: self,
+            map: f,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decodes each row into `T` via [`FromRow`], a convenience wrapper around [`map_rows`].
+    ///
+    /// ```rust,ignore
+    /// let articles: Vec<Article> = cursor.from_row::<Article>().try_collect().await?;
+    /// ```
+    ///
+    /// [`FromRow`]: crate::row::FromRow
+    /// [`map_rows`]: CursorExt::map_rows
+    fn from_row<T>(
+        self,
+    ) -> TypedCursor<
+        'c,
+        'q,
+        Self,
+        T,
+        fn(&<Self::Database as HasRow<'_>>::Row) -> crate::Result<Self::Database, T>,
+    >
+    where
+        Self: Sized,
+        T: for<'r> crate::row::FromRow<'r, <Self::Database as HasRow<'r>>::Row>,
+    {
+        self.map_rows(|row| T::from_row(row))
+    }
+
+    /// Wraps this cursor in a read-ahead buffer that prefetches up to `capacity` rows per
+    /// protocol drain instead of round-tripping once per row.
+    ///
+    /// On an empty buffer the first `next()` drains up to `capacity` elements from the wire in a
+    /// single pass, copying each row into an owned [`BoxOwnedRow`] (the borrowed driver row points
+    /// at a buffer the very next read overwrites, so read-ahead is only sound once the rows are
+    /// owned). Subsequent calls are served from memory, refilling again only once the buffer
+    /// empties. This trades `capacity * sizeof(row)` of resident memory for far fewer awaits, a win
+    /// over high-latency links and a loss for result sets consumed lazily or aborted early.
+    ///
+    /// Prefetch is driven through [`next_many`](Cursor::next_many), so the command-complete
+    /// metadata of multi-statement and `RETURNING` batches is preserved in execution order and
+    /// surfaced by the buffered cursor's own `next_many`.
+    ///
+    /// A `capacity` of `0` is clamped to `1`: a zero-length buffer would never drive the inner
+    /// cursor and would report an empty result set while leaving the connection undrained.
+    ///
+    /// Cancellation between refills is safe: the buffer holds whole decoded rows, so dropping the
+    /// [`BufferedCursor`] still leaves the inner cursor to drain any rows the server has yet to
+    /// send, returning the connection to the pool usable.
+    fn buffered(self, capacity: usize) -> BufferedCursor<'c, 'q, Self>
+    where
+        Self: Sized,
+    {
+        let capacity = capacity.max(1);
+        BufferedCursor {
+            cursor: self,
+            buffer: VecDeque::with_capacity(capacity),
+            pos: 0,
+            capacity,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'c, 'q, C> CursorExt<'c, 'q> for C where C: Cursor<'c, 'q> {}
+
+/// A [`Stream`] over the owned rows of a [`Cursor`], created by [`CursorExt::into_stream`].
+///
+/// Each poll drives one `next()` to completion and copies the row into a [`BoxOwnedRow`] before the
+/// borrow is released, so the stream hands out values that outlive the connection's current-row
+/// buffer.
+pub struct CursorStream<'c, 'q, C: Cursor<'c, 'q>> {
+    cursor: C,
+    _marker: PhantomData<(&'c (), &'q ())>,
+}
+
+impl<'c, 'q, C> Stream for CursorStream<'c, 'q, C>
+where
+    C: Cursor<'c, 'q>,
+    for<'r> <C::Database as HasRow<'r>>::Row: ToOwnedRow<C::Database>,
+{
+    type Item = crate::Result<C::Database, BoxOwnedRow<C::Database>>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        // `Cursor: Unpin`, so the stream is `Unpin` and can be driven without `unsafe`. The row's
+        // borrow never escapes this poll: it is copied into an owned row before `next` is dropped.
+        let this = self.get_mut();
+        let mut next = this.cursor.next();
+
+        match next.as_mut().poll(cx) {
+            std::task::Poll::Ready(Ok(Some(row))) => {
+                std::task::Poll::Ready(Some(Ok(row.to_owned_row())))
+            }
+            std::task::Poll::Ready(Ok(None)) => std::task::Poll::Ready(None),
+            std::task::Poll::Ready(Err(e)) => std::task::Poll::Ready(Some(Err(e))),
+            std::task::Poll::Pending => std::task::Poll::Pending,
+        }
+    }
 }
 
+/// A [`Stream`] that decodes each row of a [`Cursor`] into an owned `T`, created by
+/// [`CursorExt::map_rows`] and [`CursorExt::from_row`].
+pub struct TypedCursor<'c, 'q, C: Cursor<'c, 'q>, T, F> {
+    cursor: C,
+    map: F,
+    _marker: PhantomData<(&'c (), &'q (), fn() -> T)>,
+}
+
+impl<'c, 'q, C, T, F> Stream for TypedCursor<'c, 'q, C, T, F>
+where
+    C: Cursor<'c, 'q>,
+    F: for<'cur> FnMut(&<C::Database as HasRow<'cur>>::Row) -> crate::Result<C::Database, T> + Unpin,
+{
+    type Item = crate::Result<C::Database, T>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        // `Cursor: Unpin` and `F: Unpin`, so the adapter is `Unpin`. The conversion runs while the
+        // row's borrow is still live, yielding an owned `T` before `next` is dropped.
+        let this = self.get_mut();
+        let mut next = this.cursor.next();
+
+        match next.as_mut().poll(cx) {
+            std::task::Poll::Ready(Ok(Some(row))) => std::task::Poll::Ready(Some((this.map)(&row))),
+            std::task::Poll::Ready(Ok(None)) => std::task::Poll::Ready(None),
+            std::task::Poll::Ready(Err(e)) => std::task::Poll::Ready(Some(Err(e))),
+            std::task::Poll::Pending => std::task::Poll::Pending,
+        }
+    }
+}
+
+/// A [`Cursor`] that prefetches rows into an in-memory buffer, created by
+/// [`CursorExt::buffered`].
+pub struct BufferedCursor<'c, 'q, C: Cursor<'c, 'q>> {
+    cursor: C,
+    buffer: VecDeque<Either<C::QueryResult, BoxOwnedRow<C::Database>>>,
+    pos: usize,
+    capacity: usize,
+    done: bool,
+    _marker: PhantomData<(&'c (), &'q ())>,
+}
+
+impl<'c, 'q, C> BufferedCursor<'c, 'q, C>
+where
+    C: Cursor<'c, 'q>,
+    C::QueryResult: Clone,
+    for<'r> <C::Database as HasRow<'r>>::Row: ToOwnedRow<C::Database>,
+{
+    /// Drains up to `capacity` elements from the inner cursor into the buffer in a single pass,
+    /// copying each row into owned storage. Routed through `next_many` so command-complete metadata
+    /// is retained in order.
+    fn refill<'a>(&'a mut self) -> BoxFuture<'a, crate::Result<C::Database, ()>> {
+        Box::pin(async move {
+            self.buffer.clear();
+            self.pos = 0;
+
+            while self.buffer.len() < self.capacity {
+                match self.cursor.next_many().await? {
+                    Some(Either::Left(result)) => self.buffer.push_back(Either::Left(result)),
+                    Some(Either::Right(row)) => {
+                        self.buffer.push_back(Either::Right(row.to_owned_row()))
+                    }
+                    None => {
+                        self.done = true;
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<'c, 'q, C> private::Sealed for BufferedCursor<'c, 'q, C> where C: Cursor<'c, 'q> {}
+
+impl<'c, 'q, C> Cursor<'c, 'q> for BufferedCursor<'c, 'q, C>
+where
+    C: Cursor<'c, 'q>,
+    C::QueryResult: Clone,
+    for<'r> <C::Database as HasRow<'r>>::Row: ToOwnedRow<C::Database>,
+{
+    type Database = C::Database;
+    type QueryResult = C::QueryResult;
+
+    fn from_pool<E>(pool: &Pool<<Self::Database as Database>::Connection>, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Self::Database>,
+    {
+        C::from_pool(pool, query).buffered(DEFAULT_BUFFER_CAPACITY)
+    }
+
+    fn from_connection<E>(
+        connection: &'c mut <Self::Database as Database>::Connection,
+        query: E,
+    ) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Self::Database>,
+    {
+        C::from_connection(connection, query).buffered(DEFAULT_BUFFER_CAPACITY)
+    }
+
+    fn next<'cur>(
+        &'cur mut self,
+    ) -> BoxFuture<'cur, crate::Result<Self::Database, Option<<Self::Database as HasRow<'cur>>::Row>>>
+    {
+        Box::pin(async move {
+            loop {
+                if self.pos >= self.buffer.len() {
+                    if self.done {
+                        return Ok(None);
+                    }
+                    self.refill().await?;
+                    if self.buffer.is_empty() {
+                        return Ok(None);
+                    }
+                }
+
+                let idx = self.pos;
+                self.pos += 1;
+
+                match &self.buffer[idx] {
+                    // `next()` folds away command-complete packets and yields only rows.
+                    Either::Left(_) => continue,
+                    Either::Right(row) => return Ok(Some(row.as_row())),
+                }
+            }
+        })
+    }
+
+    fn next_many<'cur>(
+        &'cur mut self,
+    ) -> BoxFuture<
+        'cur,
+        crate::Result<
+            Self::Database,
+            Option<Either<Self::QueryResult, <Self::Database as HasRow<'cur>>::Row>>,
+        >,
+    > {
+        Box::pin(async move {
+            if self.pos >= self.buffer.len() {
+                if self.done {
+                    return Ok(None);
+                }
+                self.refill().await?;
+                if self.buffer.is_empty() {
+                    return Ok(None);
+                }
+            }
+
+            let idx = self.pos;
+            self.pos += 1;
+
+            Ok(Some(match &self.buffer[idx] {
+                Either::Left(result) => Either::Left(result.clone()),
+                Either::Right(row) => Either::Right(row.as_row()),
+            }))
+        })
+    }
+}
+
+/// Number of rows a [`BufferedCursor`] prefetches when constructed implicitly (e.g. through
+/// [`Cursor::from_pool`]).
+const DEFAULT_BUFFER_CAPACITY: usize = 64;
+
 // Prevent users from implementing the `Row` trait.
 pub(crate) mod private {
     pub trait Sealed {}